@@ -0,0 +1,142 @@
+// Copyright 2016 rust-tfidf Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use idf::IdfFromCounts;
+use prelude::{ExpandableDocument, ProcessedDocument, Tf, TfIdf};
+
+/// Document frequency statistics collected from a corpus in a single pass.
+/// Walking `docs` happens once, in [`fit`](#method.fit); afterwards, every
+/// idf lookup is an O(1) hash lookup against the frozen statistics rather
+/// than a fresh iteration over the corpus.
+pub struct FittedIdf<T> {
+  doc_freq: HashMap<T, usize>,
+  num_docs: usize,
+}
+
+impl<T> FittedIdf<T>
+where
+  T: Hash + Eq + Clone,
+{
+  /// Walks `docs` once, recording the number of documents each term
+  /// appears in and the total number of documents.
+  pub fn fit<'a, D>(docs: &'a [D]) -> FittedIdf<T>
+  where
+    D: ExpandableDocument<'a, Term = T>,
+    T: 'a,
+  {
+    let mut doc_freq: HashMap<T, usize> = HashMap::new();
+
+    for doc in docs {
+      let mut seen: HashSet<&T> = HashSet::new();
+
+      for term in doc.terms() {
+        if seen.insert(term) {
+          *doc_freq.entry((*term).clone()).or_insert(0) += 1;
+        }
+      }
+    }
+
+    FittedIdf {
+      doc_freq,
+      num_docs: docs.len(),
+    }
+  }
+
+  /// Looks up the document frequency recorded for `term`, or 0 if the term
+  /// was never seen while fitting.
+  fn doc_freq<K>(&self, term: K) -> usize
+  where
+    K: Borrow<T>,
+  {
+    self.doc_freq.get(term.borrow()).cloned().unwrap_or(0)
+  }
+}
+
+/// A tf-idf strategy bound to a [`FittedIdf`] model, so that scoring
+/// documents against the corpus requires no further corpus iteration. Any
+/// `S: TfIdf<D>` whose `Idf` strategy implements `IdfFromCounts` can be used
+/// with [`tfidf`](#method.tfidf), for example `DoubleHalfNormalizationTf` +
+/// `InverseFrequencyIdf`.
+pub struct FittedTfIdf<T> {
+  fitted: FittedIdf<T>,
+}
+
+impl<T> FittedTfIdf<T>
+where
+  T: Hash + Eq + Clone,
+{
+  /// Fits a [`FittedIdf`] model against `docs` in a single pass.
+  pub fn fit<'a, D>(docs: &'a [D]) -> FittedTfIdf<T>
+  where
+    D: ExpandableDocument<'a, Term = T>,
+    T: 'a,
+  {
+    FittedTfIdf {
+      fitted: FittedIdf::fit(docs),
+    }
+  }
+
+  /// Scores `term` within `doc` using the `S` tf-idf strategy, reading the
+  /// idf weight from the frozen statistics instead of re-walking the
+  /// corpus.
+  pub fn tfidf<D, S>(&self, term: T, doc: &D) -> f64
+  where
+    D: ProcessedDocument<Term = T>,
+    S: TfIdf<D>,
+    S::Idf: IdfFromCounts,
+  {
+    let doc_freq = self.fitted.doc_freq(&term);
+    S::Tf::tf(term, doc) * S::Idf::idf_from_counts(self.fitted.num_docs as f64, doc_freq as f64)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::FittedTfIdf;
+  use idf::InverseFrequencyIdf;
+  use prelude::{ProcessedDocument, TfIdf};
+  use test_support::TestDoc;
+  use tf::DoubleHalfNormalizationTf;
+
+  #[derive(Copy, Clone)]
+  struct TestStrategy;
+
+  impl<T> TfIdf<T> for TestStrategy
+  where
+    T: ProcessedDocument,
+  {
+    type Tf = DoubleHalfNormalizationTf;
+    type Idf = InverseFrequencyIdf;
+  }
+
+  #[test]
+  fn fitted_tfidf_matches_unfitted() {
+    let owned = vec![
+      TestDoc(vec![("this", 1), ("is", 1), ("a", 2), ("sample", 1)]),
+      TestDoc(vec![("this", 1), ("is", 1), ("another", 2), ("example", 3)]),
+    ];
+    // `FittedIdf::fit` needs `ExpandableDocument`, which (see `test_support`)
+    // is only implemented for `&'a TestDoc`, so the corpus is borrowed once
+    // here.
+    let docs: Vec<&TestDoc> = owned.iter().collect();
+
+    let fitted = FittedTfIdf::fit(&docs);
+
+    assert_eq!(
+      fitted.tfidf::<_, TestStrategy>("this", &owned[0]),
+      TestStrategy::tfidf("this", &owned[0], owned.iter())
+    );
+    assert_eq!(
+      fitted.tfidf::<_, TestStrategy>("sample", &owned[0]),
+      TestStrategy::tfidf("sample", &owned[0], owned.iter())
+    );
+  }
+}
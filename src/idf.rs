@@ -37,10 +37,35 @@ where
   }
 }
 
+/// A strategy whose idf can be computed directly from the size of the
+/// corpus and a term's document frequency, without iterating the corpus.
+/// This is what lets [`FittedIdf`](../fitted/struct.FittedIdf.html) turn a
+/// one-time walk over the corpus into O(1) idf lookups per term.
+pub trait IdfFromCounts {
+  /// Computes the idf weight given `num_docs`, the number of documents in
+  /// the corpus, and `doc_freq`, the number of documents containing the
+  /// term.
+  fn idf_from_counts(num_docs: f64, doc_freq: f64) -> f64;
+}
+
 /// Inverse frequency weighting scheme for IDF with a smoothing factor. Used
-/// internally as a marker trait.
+/// internally as a marker trait. A term with a document frequency of 0 (i.e.
+/// a term unseen in the corpus) contributes 0 rather than dividing by zero.
 pub trait InverseFrequencySmoothedIdfStrategy: SmoothingFactor {}
 
+impl<S> IdfFromCounts for S
+where
+  S: InverseFrequencySmoothedIdfStrategy,
+{
+  #[inline]
+  fn idf_from_counts(num_docs: f64, doc_freq: f64) -> f64 {
+    if doc_freq == 0f64 {
+      return 0f64;
+    }
+    (S::factor() + (num_docs / doc_freq)).ln()
+  }
+}
+
 impl<S, T> Idf<T> for S
 where
   S: InverseFrequencySmoothedIdfStrategy,
@@ -63,7 +88,7 @@ where
         t + 1f64,
       )
     });
-    (S::factor() + (ttl_docs / num_docs)).ln()
+    Self::idf_from_counts(ttl_docs, num_docs)
   }
 }
 
@@ -95,7 +120,8 @@ impl InverseFrequencySmoothedIdfStrategy for InverseFrequencySmoothIdf {}
 
 /// Inverse frequency weighting scheme for IDF. Compute `log (1 + (max nt / nt))`
 /// where `nt` is the number of times a term appears in the corpus, and `max nt`
-/// returns the most number of times any term appears in the corpus.
+/// returns the most number of times any term appears in the corpus. A term
+/// unseen in the corpus (`nt == 0`) contributes 0 rather than dividing by zero.
 #[derive(Copy, Clone)]
 pub struct InverseFrequencyMaxIdf;
 
@@ -123,12 +149,147 @@ where
         n
       }
     });
+
+    if num_docs == 0 {
+      return 0f64;
+    }
+
     let max = *counts.values().max().unwrap_or(&1);
 
     (1f64 + (max as f64 / num_docs as f64)).ln()
   }
 }
 
+/// Inverse frequency weighting scheme for IDF, as used by scikit-learn and
+/// linfa-preprocessing's `TfIdfMethod::Smooth`. Computes
+/// `log ((1 + N) / (1 + nt)) + 1` where `N` is the number of documents, and
+/// `nt` is the number of documents that contain the term. The `1 +` applied
+/// to both sides of the division models an artificial document that
+/// contains every term, which avoids a division by zero when `N == 0`. The
+/// logarithm itself is zero when `nt == N`; the `+ 1` outside the
+/// logarithm keeps terms that appear in every document from collapsing to a
+/// weight of zero entirely.
+#[derive(Copy, Clone)]
+pub struct Smooth;
+
+impl IdfFromCounts for Smooth {
+  #[inline]
+  fn idf_from_counts(num_docs: f64, doc_freq: f64) -> f64 {
+    ((1f64 + num_docs) / (1f64 + doc_freq)).ln() + 1f64
+  }
+}
+
+impl<T> Idf<T> for Smooth
+where
+  T: NaiveDocument,
+{
+  #[inline]
+  fn idf<'a, I, K>(term: K, docs: I) -> f64
+  where
+    I: Iterator<Item = &'a T>,
+    K: Borrow<T::Term>,
+    T: 'a,
+  {
+    let (num_docs, ttl_docs) = docs.fold((0f64, 0f64), |(n, t), d| {
+      (
+        if d.term_exists(term.borrow()) {
+          n + 1f64
+        } else {
+          n
+        },
+        t + 1f64,
+      )
+    });
+    Self::idf_from_counts(ttl_docs, num_docs)
+  }
+}
+
+/// Inverse frequency weighting scheme for IDF, as used by scikit-learn and
+/// linfa-preprocessing's `TfIdfMethod::NonSmooth`. Computes `log (N / nt) + 1`
+/// where `N` is the number of documents, and `nt` is the number of documents
+/// that contain the term. The `+ 1` outside the logarithm keeps terms that
+/// appear in every document from collapsing to a weight of zero. A term
+/// unseen in the corpus (`nt == 0`) contributes 0 rather than dividing by
+/// zero.
+#[derive(Copy, Clone)]
+pub struct NonSmooth;
+
+impl IdfFromCounts for NonSmooth {
+  #[inline]
+  fn idf_from_counts(num_docs: f64, doc_freq: f64) -> f64 {
+    if doc_freq == 0f64 {
+      return 0f64;
+    }
+    (num_docs / doc_freq).ln() + 1f64
+  }
+}
+
+impl<T> Idf<T> for NonSmooth
+where
+  T: NaiveDocument,
+{
+  #[inline]
+  fn idf<'a, I, K>(term: K, docs: I) -> f64
+  where
+    I: Iterator<Item = &'a T>,
+    K: Borrow<T::Term>,
+    T: 'a,
+  {
+    let (num_docs, ttl_docs) = docs.fold((0f64, 0f64), |(n, t), d| {
+      (
+        if d.term_exists(term.borrow()) {
+          n + 1f64
+        } else {
+          n
+        },
+        t + 1f64,
+      )
+    });
+    Self::idf_from_counts(ttl_docs, num_docs)
+  }
+}
+
+/// Inverse frequency weighting scheme for IDF, as used by scikit-learn and
+/// linfa-preprocessing's `TfIdfMethod::Textbook`. Computes `log (N / (1 + nt))`
+/// where `N` is the number of documents, and `nt` is the number of documents
+/// that contain the term. The `1 +` applied to the denominator models an
+/// artificial document that contains every term, which avoids a division by
+/// zero when a term appears in no document.
+#[derive(Copy, Clone)]
+pub struct Textbook;
+
+impl IdfFromCounts for Textbook {
+  #[inline]
+  fn idf_from_counts(num_docs: f64, doc_freq: f64) -> f64 {
+    (num_docs / (1f64 + doc_freq)).ln()
+  }
+}
+
+impl<T> Idf<T> for Textbook
+where
+  T: NaiveDocument,
+{
+  #[inline]
+  fn idf<'a, I, K>(term: K, docs: I) -> f64
+  where
+    I: Iterator<Item = &'a T>,
+    K: Borrow<T::Term>,
+    T: 'a,
+  {
+    let (num_docs, ttl_docs) = docs.fold((0f64, 0f64), |(n, t), d| {
+      (
+        if d.term_exists(term.borrow()) {
+          n + 1f64
+        } else {
+          n
+        },
+        t + 1f64,
+      )
+    });
+    Self::idf_from_counts(ttl_docs, num_docs)
+  }
+}
+
 #[test]
 fn idf_wiki_example_tests() {
   let mut docs = Vec::new();
@@ -140,6 +301,34 @@ fn idf_wiki_example_tests() {
   assert_eq!(InverseFrequencyIdf::idf("this", docs.iter()), 0f64);
 }
 
+#[test]
+fn idf_sklearn_compatible_mode_tests() {
+  let mut docs = Vec::new();
+
+  docs.push(vec![("this", 1), ("is", 1), ("a", 2), ("sample", 1)]);
+  docs.push(vec![("this", 1), ("is", 1), ("another", 2), ("example", 3)]);
+
+  assert_eq!(Smooth::idf("this", docs.iter()), 1f64);
+  assert_eq!(NonSmooth::idf("this", docs.iter()), 1f64);
+  assert_eq!(Textbook::idf("this", docs.iter()), (2f64 / 3f64).ln());
+
+  assert!(Smooth::idf("sample", docs.iter()) > Smooth::idf("this", docs.iter()));
+  assert!(NonSmooth::idf("sample", docs.iter()) > NonSmooth::idf("this", docs.iter()));
+  assert!(Textbook::idf("sample", docs.iter()) > Textbook::idf("this", docs.iter()));
+}
+
+#[test]
+fn idf_unseen_term_tests() {
+  let mut docs = Vec::new();
+
+  docs.push(vec![("this", 1), ("is", 1), ("a", 2), ("sample", 1)]);
+  docs.push(vec![("this", 1), ("is", 1), ("another", 2), ("example", 3)]);
+
+  assert_eq!(InverseFrequencyIdf::idf("unseen", docs.iter()), 0f64);
+  assert_eq!(InverseFrequencySmoothIdf::idf("unseen", docs.iter()), 0f64);
+  assert_eq!(NonSmooth::idf("unseen", docs.iter()), 0f64);
+}
+
 #[test]
 fn idf_wiki_example_tests_hashmap() {
   let mut docs: Vec<std::collections::HashMap<&'static str, usize>> = Vec::new();
@@ -90,6 +90,9 @@ use std::hash::Hash;
 
 mod prelude;
 
+#[cfg(test)]
+mod test_support;
+
 /// Implementations of different weighting schemes for term frequency (tf).
 /// For more information about which ones are implemented, check the Wiki
 /// link in the crate description.
@@ -100,6 +103,16 @@ pub mod tf;
 /// check the Wiki link in the crate description.
 pub mod idf;
 
+/// A corpus-level vectorizer that builds a normalized, sparse document-term
+/// matrix from a collection of documents, caching document frequencies from
+/// a single pass over the corpus instead of re-walking it per term.
+pub mod vectorizer;
+
+/// A "fit then transform" model that caches per-term document frequencies
+/// from a single pass over a corpus, so that repeated tf-idf scoring
+/// doesn't need to re-walk the corpus for every term.
+pub mod fitted;
+
 /// Default scheme for calculating tf-idf.
 #[derive(Copy, Clone)]
 pub struct TfIdfDefault;
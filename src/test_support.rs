@@ -0,0 +1,66 @@
+// Copyright 2016 rust-tfidf Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! A `TestDoc` fixture shared by `fitted` and `vectorizer`'s unit tests,
+//! since both need a document type that implements `ProcessedDocument`
+//! and `ExpandableDocument`.
+
+use prelude::{Document, ExpandableDocument, ProcessedDocument};
+
+#[derive(Clone)]
+pub struct TestDoc(pub Vec<(&'static str, usize)>);
+
+impl Document for TestDoc {
+  type Term = &'static str;
+}
+
+impl ProcessedDocument for TestDoc {
+  fn term_frequency<K>(&self, term: K) -> usize
+  where
+    K: ::std::borrow::Borrow<&'static str>,
+  {
+    match self.0.iter().find(|&&(t, _)| &t == term.borrow()) {
+      Some(&(_, c)) => c,
+      None => 0,
+    }
+  }
+
+  fn max(&self) -> Option<&&'static str> {
+    self.0.iter().max_by_key(|&&(_, c)| c).map(|&(ref t, _)| t)
+  }
+}
+
+// `terms` must hand back references that outlive the `&self` borrow of
+// any single call, so `ExpandableDocument` is implemented for
+// `&'a TestDoc` rather than `TestDoc` itself.
+impl<'a> Document for &'a TestDoc {
+  type Term = &'static str;
+}
+
+impl<'a> ProcessedDocument for &'a TestDoc {
+  fn term_frequency<K>(&self, term: K) -> usize
+  where
+    K: ::std::borrow::Borrow<&'static str>,
+  {
+    (**self).term_frequency(term)
+  }
+
+  fn max(&self) -> Option<&&'static str> {
+    (**self).max()
+  }
+}
+
+impl<'a> ExpandableDocument<'a> for &'a TestDoc {
+  type TermIterator = ::std::iter::Map<
+    ::std::slice::Iter<'a, (&'static str, usize)>,
+    fn(&'a (&'static str, usize)) -> &'a &'static str,
+  >;
+
+  fn terms(&self) -> Self::TermIterator {
+    self.0.iter().map(|&(ref t, _)| t)
+  }
+}
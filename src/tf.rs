@@ -43,8 +43,10 @@ impl<T> Tf<T> for RawFrequencyTf where T: ProcessedDocument
   }
 }
 
-/// Log normalized weighting scheme for TF. Computes `1 + log (f)` where `f` is the
-/// frequency of the term in the document.
+/// Log normalized weighting scheme for TF. Computes `log (1 + f)` where `f` is the
+/// frequency of the term in the document. The `1 +` applied inside the logarithm
+/// keeps the result defined for an unseen term (`f == 0` maps to `0` instead of
+/// `log(0) == -inf`).
 #[derive(Copy, Clone)]
 pub struct LogNormalizationTf;
 
@@ -54,7 +56,7 @@ impl<T> Tf<T> for LogNormalizationTf where T: ProcessedDocument
   fn tf<K>(term: K, doc: &T) -> f64
     where K: Borrow<T::Term>
   {
-    1f64 + (doc.term_frequency(term) as f64).ln()
+    (1f64 + doc.term_frequency(term) as f64).ln()
   }
 }
 
@@ -108,3 +110,14 @@ impl NormalizationFactor for DoubleHalfNormalizationTf {
 }
 
 impl DoubleKNormalizationTf for DoubleHalfNormalizationTf {}
+
+#[test]
+fn tf_log_normalization_tests() {
+  let mut docs = Vec::new();
+
+  docs.push(vec![("this", 1), ("is", 1), ("a", 2), ("sample", 1)]);
+
+  assert_eq!(LogNormalizationTf::tf("missing", &docs[0]), 0f64);
+  assert_eq!(LogNormalizationTf::tf("this", &docs[0]), 2f64.ln());
+  assert_eq!(LogNormalizationTf::tf("a", &docs[0]), 3f64.ln());
+}
@@ -0,0 +1,211 @@
+// Copyright 2016 rust-tfidf Developers
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use idf::IdfFromCounts;
+use prelude::{ExpandableDocument, ProcessedDocument, Tf, TfIdf};
+
+/// A row-normalization strategy applied to a document's tf-idf weights
+/// after they have been computed.
+#[derive(Copy, Clone)]
+pub enum Normalization {
+  /// Leaves the tf-idf weights as-is.
+  None,
+  /// Scales each row so the sum of the absolute weights is 1.
+  L1,
+  /// Scales each row so the euclidean norm of the weights is 1.
+  L2,
+}
+
+/// A single non-zero entry in a document-term matrix: the column (term)
+/// index, and the weight at that position.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Entry {
+  /// The column (term) index this entry belongs to.
+  pub column: usize,
+  /// The weighted, normalized tf-idf value.
+  pub weight: f64,
+}
+
+/// A sparse, tf-idf weighted document-term matrix built from a corpus of
+/// documents. `T` is the term type; the documents passed to `fit` must
+/// implement both `ProcessedDocument` and `ExpandableDocument` so that the
+/// global vocabulary can be collected.
+pub struct Vectorizer<T> {
+  vocabulary: HashMap<T, usize>,
+  rows: Vec<Vec<Entry>>,
+}
+
+impl<T> Vectorizer<T>
+where
+  T: Hash + Eq + Clone,
+{
+  /// Builds a document-term matrix from `docs`, weighting terms with the
+  /// `S` tf-idf strategy and normalizing each row with `norm`.
+  ///
+  /// The vocabulary and each term's document frequency are collected in a
+  /// single pass over `docs`; scoring then reads those cached counts
+  /// through `S::Idf`'s `IdfFromCounts` implementation rather than
+  /// re-walking the corpus for every term. The cost of each tf lookup
+  /// depends on the document type's `term_frequency` implementation.
+  pub fn fit<'a, D, S>(docs: &'a [D], norm: Normalization) -> Vectorizer<T>
+  where
+    D: ProcessedDocument<Term = T> + ExpandableDocument<'a, Term = T>,
+    S: TfIdf<D>,
+    S::Idf: IdfFromCounts,
+    T: 'a,
+  {
+    let mut vocabulary: HashMap<T, usize> = HashMap::new();
+    let mut doc_freq: HashMap<T, usize> = HashMap::new();
+
+    for doc in docs {
+      let mut seen: HashSet<&T> = HashSet::new();
+
+      for term in doc.terms() {
+        let next = vocabulary.len();
+        vocabulary.entry((*term).clone()).or_insert(next);
+
+        if seen.insert(term) {
+          *doc_freq.entry((*term).clone()).or_insert(0) += 1;
+        }
+      }
+    }
+
+    let num_docs = docs.len() as f64;
+
+    let rows = docs
+      .iter()
+      .map(|doc| {
+        let mut by_column: HashMap<usize, f64> = HashMap::new();
+
+        for term in doc.terms() {
+          let weight =
+            S::Tf::tf(term, doc) * S::Idf::idf_from_counts(num_docs, doc_freq[term] as f64);
+
+          // Keep the matrix sparse: a zero weight (e.g. a term with zero
+          // idf because it appears in every document) is a structural
+          // zero, not a stored entry.
+          if weight != 0f64 {
+            let column = vocabulary[term];
+            by_column.insert(column, weight);
+          }
+        }
+
+        let mut row: Vec<Entry> = by_column
+          .into_iter()
+          .map(|(column, weight)| Entry { column, weight })
+          .collect();
+
+        row.sort_by_key(|e| e.column);
+        normalize(&mut row, norm);
+        row
+      })
+      .collect();
+
+    Vectorizer { vocabulary, rows }
+  }
+
+  /// The mapping of term to column index used by this matrix.
+  pub fn vocabulary(&self) -> &HashMap<T, usize> {
+    &self.vocabulary
+  }
+
+  /// The (row-major) weighted, normalized entries of the matrix, one row
+  /// per document passed to `fit`.
+  pub fn rows(&self) -> &[Vec<Entry>] {
+    &self.rows
+  }
+}
+
+fn normalize(row: &mut [Entry], norm: Normalization) {
+  let factor = match norm {
+    Normalization::None => return,
+    Normalization::L1 => row.iter().map(|e| e.weight.abs()).sum::<f64>(),
+    Normalization::L2 => row.iter().map(|e| e.weight * e.weight).sum::<f64>().sqrt(),
+  };
+
+  if factor == 0f64 {
+    return;
+  }
+
+  for entry in row.iter_mut() {
+    entry.weight /= factor;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Normalization, Vectorizer};
+  use idf::InverseFrequencyIdf;
+  use prelude::{ProcessedDocument, TfIdf};
+  use test_support::TestDoc;
+  use tf::RawFrequencyTf;
+
+  #[derive(Copy, Clone)]
+  struct TestStrategy;
+
+  impl<T> TfIdf<T> for TestStrategy
+  where
+    T: ProcessedDocument,
+  {
+    type Tf = RawFrequencyTf;
+    type Idf = InverseFrequencyIdf;
+  }
+
+  fn corpus() -> Vec<TestDoc> {
+    vec![
+      TestDoc(vec![("common", 1)]),
+      TestDoc(vec![("common", 1), ("other", 1)]),
+    ]
+  }
+
+  #[test]
+  fn vectorizer_assigns_column_indices_in_first_seen_order() {
+    let owned = corpus();
+    let docs: Vec<&TestDoc> = owned.iter().collect();
+
+    let vectorizer = Vectorizer::fit::<_, TestStrategy>(&docs, Normalization::None);
+
+    assert_eq!(vectorizer.vocabulary()[&"common"], 0);
+    assert_eq!(vectorizer.vocabulary()[&"other"], 1);
+  }
+
+  #[test]
+  fn vectorizer_drops_zero_weight_entries() {
+    let owned = corpus();
+    let docs: Vec<&TestDoc> = owned.iter().collect();
+
+    let vectorizer = Vectorizer::fit::<_, TestStrategy>(&docs, Normalization::None);
+    let rows = vectorizer.rows();
+
+    // "common" appears in every document, so `InverseFrequencyIdf` weighs
+    // it to zero; a structural zero shouldn't be stored at all, so the
+    // first row (entirely "common") ends up empty.
+    assert!(rows[0].is_empty());
+    assert_eq!(rows[1].len(), 1);
+  }
+
+  #[test]
+  fn vectorizer_l2_normalizes_rows() {
+    let owned = corpus();
+    let docs: Vec<&TestDoc> = owned.iter().collect();
+
+    let vectorizer = Vectorizer::fit::<_, TestStrategy>(&docs, Normalization::L2);
+    let rows = vectorizer.rows();
+
+    // The only surviving entry in the second row (for "other") should end
+    // up as the sole contributor to an L2 norm of 1.
+    let norm = rows[1]
+      .iter()
+      .map(|e| e.weight * e.weight)
+      .sum::<f64>()
+      .sqrt();
+    assert!((norm - 1f64).abs() < 1e-12);
+  }
+}